@@ -16,18 +16,95 @@ fn log_file() -> PathBuf {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(from = "SessionRepr")]
 struct Session {
+    id: u64,
     tag: String,
-    start: i64, // UNIX timestamp (UTC seconds)
+    /// (start, end) pairs; at most the last one may be open (`end == None`).
+    intervals: Vec<(i64, Option<i64>)>,
+    /// Set by `Pause` and cleared by `Resume`/`Stop`; distinguishes a session
+    /// that's merely paused (and so can be resumed) from one that's finished.
+    #[serde(default)]
+    paused: bool,
+}
+
+/// On-disk shape, accepting both the current `intervals` layout and the
+/// original single `start`/`end` layout so old `log.json` files keep working.
+#[derive(Deserialize)]
+struct SessionRepr {
+    #[serde(default)]
+    id: u64,
+    tag: String,
+    #[serde(default)]
+    intervals: Option<Vec<(i64, Option<i64>)>>,
+    #[serde(default)]
+    start: Option<i64>,
+    #[serde(default)]
     end: Option<i64>,
+    #[serde(default)]
+    paused: bool,
+}
+
+impl From<SessionRepr> for Session {
+    fn from(r: SessionRepr) -> Self {
+        let intervals = r
+            .intervals
+            .unwrap_or_else(|| vec![(r.start.unwrap_or(0), r.end)]);
+        Session {
+            id: r.id,
+            tag: r.tag,
+            intervals,
+            paused: r.paused,
+        }
+    }
 }
 
 impl Session {
-    fn duration(&self) -> Option<Duration> {
-        self.end.map(|e| Duration::seconds(e - self.start))
+    /// True while the last interval has no end, i.e. the activity is running.
+    fn is_open(&self) -> bool {
+        self.intervals.last().is_some_and(|(_, end)| end.is_none())
+    }
+
+    fn first_start(&self) -> i64 {
+        self.intervals.first().map_or(0, |(start, _)| *start)
+    }
+
+    fn last_end(&self) -> Option<i64> {
+        self.intervals.last().and_then(|(_, end)| *end)
+    }
+
+    /// Sum of all closed intervals, plus now-minus-start for an open one.
+    fn duration(&self) -> Duration {
+        let secs: i64 = self
+            .intervals
+            .iter()
+            .map(|(start, end)| end.unwrap_or_else(|| Utc::now().timestamp()) - start)
+            .sum();
+        Duration::seconds(secs)
     }
 }
 
+fn next_id(log: &[Session]) -> u64 {
+    log.iter().map(|s| s.id).max().map_or(1, |m| m + 1)
+}
+
+/// Assign fresh unique ids to any session whose id is unset (pre-migration
+/// `log.json`) or collides with an earlier one. Returns whether anything changed.
+fn migrate_ids(log: &mut [Session]) -> bool {
+    let mut next = log.iter().map(|s| s.id).max().unwrap_or(0) + 1;
+    let mut seen: std::collections::HashSet<u64> = std::collections::HashSet::new();
+    let mut changed = false;
+    for s in log.iter_mut() {
+        if s.id == 0 || !seen.insert(s.id) {
+            s.id = next;
+            seen.insert(next);
+            next += 1;
+            changed = true;
+        }
+    }
+    changed
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Simple Work‑Hours Logger", long_about = None)]
 struct Cli {
@@ -38,9 +115,22 @@ struct Cli {
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Start logging a new activity tagged <TAG>
-    Start { tag: String },
+    Start {
+        tag: String,
+        /// Backdate the start time (e.g. "09:00", "2 hours ago", "2024-01-02 09:00")
+        #[arg(long)]
+        since: Option<String>,
+    },
     /// Stop the currently running activity
-    Stop,
+    Stop {
+        /// Backdate the stop time (e.g. "5pm", "30 minutes ago")
+        #[arg(long)]
+        at: Option<String>,
+    },
+    /// Pause the currently running activity without ending its session
+    Pause,
+    /// Resume the most recently paused activity
+    Resume,
     /// Show current activity status
     Status,
     /// Reset (discard) the current activity without logging it
@@ -48,12 +138,169 @@ enum Commands {
     /// Show the location of the log file
     Path,
     /// Log custom hours for a task (e.g., "worklog log mytask 2.5")
-    Log { tag: String, hours: f64 },
+    Log {
+        tag: String,
+        hours: f64,
+        /// The time the logged hours ended (defaults to now), e.g. "yesterday 5pm"
+        #[arg(long)]
+        until: Option<String>,
+    },
     /// Show a report – default: daily
     Report {
         #[arg(value_parser = ["daily", "weekly", "monthly"], default_value = "daily")]
         period: String,
+        /// Look back N buckets of `period` (e.g. --offset 1 weekly = last week)
+        #[arg(long, default_value_t = 0)]
+        offset: i64,
+        /// Explicit start of the reporting window, used together with --to
+        #[arg(long)]
+        from: Option<String>,
+        /// Explicit end of the reporting window, used together with --from
+        #[arg(long)]
+        to: Option<String>,
+    },
+    /// Summarize the trailing window of DAYS days, with per-day and per-tag totals
+    Stats { days: i64 },
+    /// Export a week of sessions as a calendar grid (markdown or html)
+    Export {
+        #[arg(long, value_parser = ["markdown", "html"])]
+        format: String,
+        /// Weeks back from the current week (0 = this week)
+        #[arg(long, default_value_t = 0)]
+        period: i64,
+        /// Write to this path instead of stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
     },
+    /// List all logged sessions
+    List,
+    /// Show the most recently ended sessions, newest first
+    Recent {
+        #[arg(default_value_t = 5)]
+        count: usize,
+    },
+    /// Delete a logged session by id
+    Delete { id: u64 },
+    /// Edit a logged session's tag, start, or end time
+    Edit {
+        id: u64,
+        #[arg(long)]
+        tag: Option<String>,
+        /// New start time, e.g. "09:00" or "2026-07-20 09:00"
+        #[arg(long)]
+        start: Option<String>,
+        /// New end time, or "none" to reopen the session
+        #[arg(long)]
+        end: Option<String>,
+    },
+}
+
+/// Parse a small set of natural-language time expressions into a UTC timestamp:
+/// a bare clock time ("09:00", "5pm"), relative phrases ("2 hours ago"),
+/// "today"/"yesterday" optionally followed by a clock time, and absolute
+/// "YYYY-MM-DD[ HH:MM]" forms.
+fn parse_natural_time(input: &str) -> Result<i64, String> {
+    let s = input.trim();
+    let lower = s.to_lowercase();
+
+    if let Some(rest) = lower.strip_prefix("yesterday") {
+        let date = (Utc::now() - Duration::days(1)).date_naive();
+        return resolve_date_and_time(date, rest.trim());
+    }
+    if let Some(rest) = lower.strip_prefix("today") {
+        let date = Utc::now().date_naive();
+        return resolve_date_and_time(date, rest.trim());
+    }
+    if lower.ends_with("ago") {
+        return parse_relative_ago(&lower);
+    }
+    if let Some(ts) = parse_absolute(s) {
+        return Ok(ts);
+    }
+    if let Some(time) = parse_clock(s) {
+        let today = Utc::now().date_naive();
+        let mut ts = Utc.from_utc_datetime(&today.and_time(time)).timestamp();
+        if ts > Utc::now().timestamp() {
+            ts -= 86_400;
+        }
+        return Ok(ts);
+    }
+
+    Err(format!("Could not parse time expression: '{}'", input))
+}
+
+fn resolve_date_and_time(date: chrono::NaiveDate, rest: &str) -> Result<i64, String> {
+    let time = if rest.is_empty() {
+        chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    } else {
+        parse_clock(rest).ok_or_else(|| format!("Could not parse time '{}'", rest))?
+    };
+    Ok(Utc.from_utc_datetime(&date.and_time(time)).timestamp())
+}
+
+fn parse_relative_ago(lower: &str) -> Result<i64, String> {
+    let parts: Vec<&str> = lower.split_whitespace().collect();
+    if parts.len() < 3 {
+        return Err(format!("Could not parse relative time: '{}'", lower));
+    }
+    let n: i64 = parts[0]
+        .parse()
+        .map_err(|_| format!("Invalid number in '{}'", lower))?;
+    let delta = if parts[1].starts_with("hour") {
+        Duration::hours(n)
+    } else if parts[1].starts_with("min") {
+        Duration::minutes(n)
+    } else if parts[1].starts_with("day") {
+        Duration::days(n)
+    } else {
+        return Err(format!("Unknown time unit '{}'", parts[1]));
+    };
+    Ok((Utc::now() - delta).timestamp())
+}
+
+fn parse_absolute(s: &str) -> Option<i64> {
+    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M") {
+        return Some(Utc.from_utc_datetime(&dt).timestamp());
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Some(
+            Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+                .timestamp(),
+        );
+    }
+    None
+}
+
+/// Parse a clock time: 24-hour "HH:MM", or 12-hour "H[:MM]am"/"H[:MM] pm"
+/// (chrono's `%I%p`/`%I %p` formats can't actually match a bare hour+meridiem,
+/// so the am/pm form is parsed by hand instead).
+fn parse_clock(s: &str) -> Option<chrono::NaiveTime> {
+    let trimmed = s.trim();
+    if let Ok(t) = chrono::NaiveTime::parse_from_str(trimmed, "%H:%M") {
+        return Some(t);
+    }
+
+    let lower = trimmed.to_lowercase();
+    for suffix in ["am", "pm"] {
+        let Some(rest) = lower.strip_suffix(suffix) else {
+            continue;
+        };
+        let rest = rest.trim();
+        let (hour_str, minute_str) = rest.split_once(':').unwrap_or((rest, "0"));
+        let mut hour: u32 = hour_str.trim().parse().ok()?;
+        let minute: u32 = minute_str.trim().parse().ok()?;
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        if suffix == "pm" && hour != 12 {
+            hour += 12;
+        } else if suffix == "am" && hour == 12 {
+            hour = 0;
+        }
+        return chrono::NaiveTime::from_hms_opt(hour, minute, 0);
+    }
+
+    None
 }
 
 fn load_log() -> Vec<Session> {
@@ -62,7 +309,11 @@ fn load_log() -> Vec<Session> {
         return Vec::new();
     }
     let data = fs::read_to_string(path).expect("cannot read log");
-    serde_json::from_str(&data).unwrap_or_default()
+    let mut log: Vec<Session> = serde_json::from_str(&data).unwrap_or_default();
+    if migrate_ids(&mut log) {
+        save_log(&log);
+    }
+    log
 }
 
 fn save_log(log: &[Session]) {
@@ -77,19 +328,29 @@ fn format_duration(seconds: i64) -> String {
     format!("{:02}:{:02}:{:02}", hours, minutes, secs)
 }
 
-fn cmd_start(tag: String) {
+fn cmd_start(tag: String, since: Option<String>) {
     let mut log = load_log();
-    if log.iter().any(|s| s.end.is_none()) {
+    if log.iter().any(|s| s.is_open()) {
         eprintln!("Existing session still running. Stop it first.");
         return;
     }
 
-    // Create and save the session immediately
-    let start_time = Utc::now().timestamp();
+    let start_time = match since {
+        Some(expr) => match parse_natural_time(&expr) {
+            Ok(ts) => ts,
+            Err(e) => {
+                eprintln!("{}", e);
+                return;
+            }
+        },
+        None => Utc::now().timestamp(),
+    };
+
     log.push(Session {
+        id: next_id(&log),
         tag: tag.clone(),
-        start: start_time,
-        end: None,
+        intervals: vec![(start_time, None)],
+        paused: false,
     });
     save_log(&log);
 
@@ -114,12 +375,28 @@ fn cmd_start(tag: String) {
     }
 }
 
-fn cmd_stop() {
+fn cmd_stop(at: Option<String>) {
     let mut log = load_log();
-    match log.iter_mut().find(|s| s.end.is_none()) {
+    match log.iter_mut().find(|s| s.is_open()) {
         Some(s) => {
+            let end_time = match at {
+                Some(expr) => match parse_natural_time(&expr) {
+                    Ok(ts) => ts,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return;
+                    }
+                },
+                None => Utc::now().timestamp(),
+            };
+            let interval = s.intervals.last_mut().unwrap();
+            if end_time < interval.0 {
+                eprintln!("Computed stop time is before the running interval's start time.");
+                return;
+            }
             let tag = s.tag.clone();
-            s.end = Some(Utc::now().timestamp());
+            interval.1 = Some(end_time);
+            s.paused = false;
             save_log(&log);
             println!("Stopped {}.", tag);
         }
@@ -127,12 +404,54 @@ fn cmd_stop() {
     }
 }
 
+fn cmd_pause() {
+    let mut log = load_log();
+    match log.iter_mut().find(|s| s.is_open()) {
+        Some(s) => {
+            let now = Utc::now().timestamp();
+            s.intervals.last_mut().unwrap().1 = Some(now);
+            s.paused = true;
+            let tag = s.tag.clone();
+            save_log(&log);
+            println!("Paused {}.", tag);
+        }
+        None => eprintln!("No running session."),
+    }
+}
+
+fn cmd_resume() {
+    let mut log = load_log();
+    if log.iter().any(|s| s.is_open()) {
+        eprintln!("A session is already running. Pause or stop it first.");
+        return;
+    }
+
+    let pos = log
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.paused)
+        .filter_map(|(i, s)| s.last_end().map(|end| (i, end)))
+        .max_by_key(|(_, end)| *end)
+        .map(|(i, _)| i);
+
+    match pos {
+        Some(i) => {
+            let now = Utc::now().timestamp();
+            log[i].intervals.push((now, None));
+            log[i].paused = false;
+            let tag = log[i].tag.clone();
+            save_log(&log);
+            println!("Resumed {}.", tag);
+        }
+        None => eprintln!("No paused session to resume."),
+    }
+}
+
 fn cmd_status() {
     let log = load_log();
-    match log.iter().find(|s| s.end.is_none()) {
+    match log.iter().find(|s| s.is_open()) {
         Some(s) => {
-            let duration = Utc::now().timestamp() - s.start;
-            let hours = duration as f64 / 3600.0;
+            let hours = s.duration().num_seconds() as f64 / 3600.0;
             println!("Currently working on: {} ({:.2}h)", s.tag, hours);
         }
         None => println!("No active session."),
@@ -141,7 +460,7 @@ fn cmd_status() {
 
 fn cmd_reset() {
     let mut log = load_log();
-    match log.iter().position(|s| s.end.is_none()) {
+    match log.iter().position(|s| s.is_open()) {
         Some(pos) => {
             let session = log.remove(pos);
             save_log(&log);
@@ -155,43 +474,113 @@ fn cmd_path() {
     println!("{}", log_file().display());
 }
 
-fn cmd_log(tag: String, hours: f64) {
+fn cmd_log(tag: String, hours: f64, until: Option<String>) {
     if hours <= 0.0 {
         eprintln!("Hours must be positive.");
         return;
     }
 
     let mut log = load_log();
-    let now = Utc::now().timestamp();
+    let now = match until {
+        Some(expr) => match parse_natural_time(&expr) {
+            Ok(ts) => ts,
+            Err(e) => {
+                eprintln!("{}", e);
+                return;
+            }
+        },
+        None => Utc::now().timestamp(),
+    };
     let duration_seconds = (hours * 3600.0) as i64;
     let start_time = now - duration_seconds;
 
+    if start_time > now {
+        eprintln!("Computed start time is after the end time.");
+        return;
+    }
+
     log.push(Session {
+        id: next_id(&log),
         tag: tag.clone(),
-        start: start_time,
-        end: Some(now),
+        intervals: vec![(start_time, Some(now))],
+        paused: false,
     });
 
     save_log(&log);
     println!("Logged {:.2} hours for '{}'.", hours, tag);
 }
 
-fn within_period(ts: i64, period: &str) -> bool {
-    let dt = Utc.timestamp_opt(ts, 0).single().unwrap();
+fn start_of_day(date: chrono::NaiveDate) -> i64 {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+        .timestamp()
+}
+
+/// Resolve the `[window_start, window_end)` a report should cover, either from
+/// an explicit `--from`/`--to` pair or by walking `offset` buckets of `period`
+/// back from the current day/week/month.
+fn resolve_window(
+    period: &str,
+    offset: i64,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<(i64, i64), String> {
+    if from.is_some() || to.is_some() {
+        let from = from.ok_or("`--from` is required when `--to` is given")?;
+        let to = to.ok_or("`--to` is required when `--from` is given")?;
+        let start = parse_natural_time(&from)?;
+        let end = parse_natural_time(&to)?;
+        if start > end {
+            return Err("`--from` must be before `--to`.".to_string());
+        }
+        return Ok((start, end));
+    }
+
     let now = Utc::now();
     match period {
-        "daily" => dt.date_naive() == now.date_naive(),
+        "daily" => {
+            let day = now.date_naive() - Duration::days(offset);
+            let start = start_of_day(day);
+            Ok((start, start + 86_400))
+        }
         "weekly" => {
-            let w1 = dt.iso_week();
-            let w2 = now.iso_week();
-            w1.year() == w2.year() && w1.week() == w2.week()
+            let days_from_monday = now.weekday().num_days_from_monday() as i64;
+            let this_monday = now.date_naive() - Duration::days(days_from_monday);
+            let week_start = this_monday - Duration::days(offset * 7);
+            let start = start_of_day(week_start);
+            Ok((start, start + 7 * 86_400))
         }
-        "monthly" => dt.year() == now.year() && dt.month() == now.month(),
-        _ => false,
+        "monthly" => {
+            let months_total = now.year() * 12 + now.month() as i32 - 1 - offset as i32;
+            let start_year = months_total.div_euclid(12);
+            let start_month = months_total.rem_euclid(12) as u32 + 1;
+            let start_date = chrono::NaiveDate::from_ymd_opt(start_year, start_month, 1).unwrap();
+            let end_months_total = months_total + 1;
+            let end_year = end_months_total.div_euclid(12);
+            let end_month = end_months_total.rem_euclid(12) as u32 + 1;
+            let end_date = chrono::NaiveDate::from_ymd_opt(end_year, end_month, 1).unwrap();
+            Ok((start_of_day(start_date), start_of_day(end_date)))
+        }
+        _ => Err(format!("Unknown period '{}'.", period)),
     }
 }
 
-fn cmd_report(period: String) {
+fn format_window_bound(ts: i64) -> String {
+    Utc.timestamp_opt(ts, 0)
+        .single()
+        .unwrap()
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+fn cmd_report(period: String, offset: i64, from: Option<String>, to: Option<String>) {
+    let (window_start, window_end) = match resolve_window(&period, offset, from, to) {
+        Ok(window) => window,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
     let mut table = Table::new();
     table.set_header(vec!["Tag", "Total (h)"]);
 
@@ -200,38 +589,451 @@ fn cmd_report(period: String) {
     // Aggregate seconds per tag
     let mut agg: std::collections::HashMap<String, i64> = std::collections::HashMap::new();
 
-    for s in log.iter().filter(|s| s.end.is_some()) {
-        if within_period(s.start, &period) || within_period(s.end.unwrap(), &period) {
-            let dur = s.duration().unwrap().num_seconds();
-            *agg.entry(s.tag.clone()).or_insert(0) += dur;
+    let in_window = |ts: i64| ts >= window_start && ts < window_end;
+    for s in &log {
+        for (start, end) in &s.intervals {
+            let Some(end) = end else { continue };
+            if in_window(*start) || in_window(*end) {
+                *agg.entry(s.tag.clone()).or_insert(0) += end - start;
+            }
         }
     }
 
+    let header = format!(
+        "{} report ({} to {})",
+        period.to_uppercase(),
+        format_window_bound(window_start),
+        format_window_bound(window_end)
+    );
+
     if agg.is_empty() {
-        println!("No completed sessions for {} period.", period);
+        println!("{}\nNo completed sessions in this window.", header);
         return;
     }
 
     let mut pairs: Vec<(String, i64)> = agg.into_iter().collect();
-    pairs.sort_by(|a, b| b.1.cmp(&a.1));
+    pairs.sort_by_key(|(_, secs)| std::cmp::Reverse(*secs));
 
     for (tag, secs) in pairs {
         let hrs = secs as f64 / 3600.0;
         table.add_row(vec![Cell::new(tag), Cell::new(format!("{:.2}", hrs))]);
     }
 
-    println!("{} report\n{}", period.to_uppercase(), table);
+    println!("{}\n{}", header, table);
+}
+
+fn cmd_stats(days: i64) {
+    if days <= 0 {
+        eprintln!("Days must be positive.");
+        return;
+    }
+
+    let log = load_log();
+    let today = Utc::now().date_naive();
+    let start_date = today - Duration::days(days - 1);
+
+    let mut daily: std::collections::HashMap<chrono::NaiveDate, i64> =
+        std::collections::HashMap::new();
+    let mut tag_totals: std::collections::HashMap<String, i64> = std::collections::HashMap::new();
+    let mut grand_total: i64 = 0;
+
+    for s in &log {
+        for (start, end) in &s.intervals {
+            let Some(end) = end else { continue };
+            let day = Utc.timestamp_opt(*start, 0).single().unwrap().date_naive();
+            if day < start_date || day > today {
+                continue;
+            }
+            let secs = end - start;
+            *daily.entry(day).or_insert(0) += secs;
+            *tag_totals.entry(s.tag.clone()).or_insert(0) += secs;
+            grand_total += secs;
+        }
+    }
+
+    let mut day_table = Table::new();
+    day_table.set_header(vec!["Date", "Total"]);
+    let mut cursor = start_date;
+    while cursor <= today {
+        let secs = daily.get(&cursor).copied().unwrap_or(0);
+        day_table.add_row(vec![
+            Cell::new(cursor.to_string()),
+            Cell::new(format_duration(secs)),
+        ]);
+        cursor += Duration::days(1);
+    }
+    println!("Last {} days\n{}", days, day_table);
+
+    if grand_total == 0 {
+        println!("\nNo tracked time in this window.");
+        return;
+    }
+
+    let mean_hours_per_day = (grand_total as f64 / 3600.0) / days as f64;
+    println!("\nTotal tracked: {}", format_duration(grand_total));
+    println!("Average per day: {:.2}h", mean_hours_per_day);
+
+    let mut tag_pairs: Vec<(String, i64)> = tag_totals.into_iter().collect();
+    tag_pairs.sort_by_key(|(_, secs)| std::cmp::Reverse(*secs));
+    let mut tag_table = Table::new();
+    tag_table.set_header(vec!["Tag", "Total"]);
+    for (tag, secs) in tag_pairs {
+        tag_table.add_row(vec![Cell::new(tag), Cell::new(format_duration(secs))]);
+    }
+    println!("\nBy tag\n{}", tag_table);
+
+    if let Some((busiest_day, secs)) = daily.iter().max_by_key(|(_, &secs)| secs) {
+        println!("\nBusiest day: {} ({})", busiest_day, format_duration(*secs));
+    }
+}
+
+fn format_hhmm(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    format!("{:02}:{:02}", hours, minutes)
+}
+
+/// Escape a tag for safe interpolation into the HTML export's `<table>`.
+fn escape_html(tag: &str) -> String {
+    tag.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escape a tag for safe interpolation into a markdown table cell: replace
+/// newlines with spaces and escape `|` so it can't corrupt the cell's column structure.
+fn escape_markdown_cell(tag: &str) -> String {
+    tag.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+fn render_markdown_week(
+    monday: chrono::NaiveDate,
+    day_sessions: &[Vec<(String, i64)>],
+    day_totals: &[i64; 7],
+    week_total: i64,
+) -> String {
+    let mut header = String::from("|");
+    let mut divider = String::from("|");
+    for d in 0..7 {
+        let date = monday + Duration::days(d as i64);
+        header.push_str(&format!(" {} |", date.format("%a %Y-%m-%d")));
+        divider.push_str(" --- |");
+    }
+
+    let mut row = String::from("|");
+    for d in 0..7 {
+        let mut cell = String::new();
+        for (tag, secs) in &day_sessions[d] {
+            cell.push_str(&format!(
+                "{} {}<br>",
+                escape_markdown_cell(tag),
+                format_hhmm(*secs)
+            ));
+        }
+        cell.push_str(&format!("**Total: {}**", format_hhmm(day_totals[d])));
+        row.push_str(&format!(" {} |", cell));
+    }
+
+    format!(
+        "{}\n{}\n{}\n\nWeek total: {}\n",
+        header,
+        divider,
+        row,
+        format_hhmm(week_total)
+    )
+}
+
+fn render_html_week(
+    monday: chrono::NaiveDate,
+    day_sessions: &[Vec<(String, i64)>],
+    day_totals: &[i64; 7],
+    week_total: i64,
+) -> String {
+    let mut out = String::from("<table style=\"border-collapse: collapse; width: 100%;\">\n  <tr>\n");
+    for d in 0..7 {
+        let date = monday + Duration::days(d as i64);
+        out.push_str(&format!(
+            "    <th style=\"border: 1px solid #ccc; padding: 6px;\">{}</th>\n",
+            date.format("%a %Y-%m-%d")
+        ));
+    }
+    out.push_str("  </tr>\n  <tr>\n");
+    for d in 0..7 {
+        out.push_str(
+            "    <td style=\"border: 1px solid #ccc; padding: 6px; vertical-align: top;\">",
+        );
+        for (tag, secs) in &day_sessions[d] {
+            out.push_str(&format!(
+                "{} {}<br>",
+                escape_html(tag),
+                format_hhmm(*secs)
+            ));
+        }
+        out.push_str(&format!(
+            "<strong>Total: {}</strong>",
+            format_hhmm(day_totals[d])
+        ));
+        out.push_str("</td>\n");
+    }
+    out.push_str("  </tr>\n</table>\n");
+    out.push_str(&format!("<p>Week total: {}</p>\n", format_hhmm(week_total)));
+    out
+}
+
+fn cmd_export(format: String, period: i64, out: Option<PathBuf>) {
+    let (window_start, _) = match resolve_window("weekly", period, None, None) {
+        Ok(window) => window,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+    let monday = Utc.timestamp_opt(window_start, 0).single().unwrap().date_naive();
+
+    let log = load_log();
+    let mut day_sessions: Vec<Vec<(String, i64)>> = vec![Vec::new(); 7];
+    let mut day_totals = [0i64; 7];
+    let mut week_total: i64 = 0;
+
+    for s in &log {
+        for (start, end) in &s.intervals {
+            let Some(end) = end else { continue };
+            let day = Utc.timestamp_opt(*start, 0).single().unwrap().date_naive();
+            let offset_days = (day - monday).num_days();
+            if !(0..7).contains(&offset_days) {
+                continue;
+            }
+            let idx = offset_days as usize;
+            let secs = end - start;
+            day_sessions[idx].push((s.tag.clone(), secs));
+            day_totals[idx] += secs;
+            week_total += secs;
+        }
+    }
+
+    let content = match format.as_str() {
+        "markdown" => render_markdown_week(monday, &day_sessions, &day_totals, week_total),
+        "html" => render_html_week(monday, &day_sessions, &day_totals, week_total),
+        other => {
+            eprintln!("Unknown export format '{}'.", other);
+            return;
+        }
+    };
+
+    match out {
+        Some(path) => match fs::write(&path, content) {
+            Ok(()) => println!("Wrote {}", path.display()),
+            Err(e) => eprintln!("Failed to write {}: {}", path.display(), e),
+        },
+        None => println!("{}", content),
+    }
+}
+
+fn cmd_list() {
+    let log = load_log();
+    if log.is_empty() {
+        println!("No sessions logged.");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["ID", "Tag", "Start", "End", "Intervals", "Duration"]);
+    for s in &log {
+        let end_str = match s.last_end() {
+            Some(e) => format_window_bound(e),
+            None => "running".to_string(),
+        };
+        table.add_row(vec![
+            Cell::new(s.id),
+            Cell::new(&s.tag),
+            Cell::new(format_window_bound(s.first_start())),
+            Cell::new(end_str),
+            Cell::new(s.intervals.len()),
+            Cell::new(format_duration(s.duration().num_seconds())),
+        ]);
+    }
+    println!("{}", table);
+}
+
+fn humanize_ago(delta_secs: i64) -> String {
+    if delta_secs < 60 {
+        "just now".to_string()
+    } else if delta_secs < 3_600 {
+        format!("{}m ago", delta_secs / 60)
+    } else if delta_secs < 86_400 {
+        format!("{}h ago", delta_secs / 3_600)
+    } else {
+        format!("{}d ago", delta_secs / 86_400)
+    }
+}
+
+fn cmd_recent(count: usize) {
+    let log = load_log();
+    let mut ended: Vec<&Session> = log.iter().filter(|s| s.last_end().is_some()).collect();
+    ended.sort_by_key(|s| std::cmp::Reverse(s.last_end().unwrap()));
+    ended.truncate(count);
+
+    if ended.is_empty() {
+        println!("No completed sessions yet.");
+        return;
+    }
+
+    let now = Utc::now().timestamp();
+    let mut table = Table::new();
+    table.set_header(vec!["Tag", "Duration", "Ended"]);
+    for s in ended {
+        let end = s.last_end().unwrap();
+        table.add_row(vec![
+            Cell::new(&s.tag),
+            Cell::new(format_duration(s.duration().num_seconds())),
+            Cell::new(humanize_ago(now - end)),
+        ]);
+    }
+    println!("{}", table);
+}
+
+fn cmd_delete(id: u64) {
+    let mut log = load_log();
+    match log.iter().position(|s| s.id == id) {
+        Some(pos) => {
+            let session = log.remove(pos);
+            save_log(&log);
+            println!("Deleted session {} ({}).", id, session.tag);
+        }
+        None => eprintln!("No session with id {}.", id),
+    }
+}
+
+fn cmd_edit(id: u64, tag: Option<String>, start: Option<String>, end: Option<String>) {
+    let mut log = load_log();
+    let pos = match log.iter().position(|s| s.id == id) {
+        Some(pos) => pos,
+        None => {
+            eprintln!("No session with id {}.", id);
+            return;
+        }
+    };
+
+    let new_start = match start {
+        Some(expr) => match parse_natural_time(&expr) {
+            Ok(ts) => Some(ts),
+            Err(e) => {
+                eprintln!("{}", e);
+                return;
+            }
+        },
+        None => None,
+    };
+
+    let new_end: Option<Option<i64>> = match end {
+        Some(expr) if expr.eq_ignore_ascii_case("none") || expr.eq_ignore_ascii_case("open") => {
+            Some(None)
+        }
+        Some(expr) => match parse_natural_time(&expr) {
+            Ok(ts) => Some(Some(ts)),
+            Err(e) => {
+                eprintln!("{}", e);
+                return;
+            }
+        },
+        None => None,
+    };
+
+    if new_end == Some(None) && log.iter().any(|s| s.id != id && s.is_open()) {
+        eprintln!("Another session is already running; cannot reopen this one.");
+        return;
+    }
+
+    let session = &mut log[pos];
+    if let Some(t) = tag {
+        session.tag = t;
+    }
+    if let Some(s) = new_start {
+        if let Some(first) = session.intervals.first_mut() {
+            first.0 = s;
+        }
+    }
+    if let Some(e) = new_end {
+        if let Some(last) = session.intervals.last_mut() {
+            last.1 = e;
+        }
+    }
+
+    if let Some(&(start, Some(end))) = session.intervals.last() {
+        if start > end {
+            eprintln!("Computed start time is after the end time.");
+            return;
+        }
+    }
+
+    save_log(&log);
+    println!("Updated session {}.", id);
 }
 
 fn main() {
     let cli = Cli::parse();
     match cli.command {
-        Commands::Start { tag } => cmd_start(tag),
-        Commands::Stop => cmd_stop(),
+        Commands::Start { tag, since } => cmd_start(tag, since),
+        Commands::Stop { at } => cmd_stop(at),
+        Commands::Pause => cmd_pause(),
+        Commands::Resume => cmd_resume(),
         Commands::Status => cmd_status(),
         Commands::Reset => cmd_reset(),
         Commands::Path => cmd_path(),
-        Commands::Log { tag, hours } => cmd_log(tag, hours),
-        Commands::Report { period } => cmd_report(period),
+        Commands::Log { tag, hours, until } => cmd_log(tag, hours, until),
+        Commands::Report {
+            period,
+            offset,
+            from,
+            to,
+        } => cmd_report(period, offset, from, to),
+        Commands::Stats { days } => cmd_stats(days),
+        Commands::Export { format, period, out } => cmd_export(format, period, out),
+        Commands::List => cmd_list(),
+        Commands::Recent { count } => cmd_recent(count),
+        Commands::Delete { id } => cmd_delete(id),
+        Commands::Edit {
+            id,
+            tag,
+            start,
+            end,
+        } => cmd_edit(id, tag, start, end),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_clock_bare_meridiem() {
+        assert_eq!(parse_clock("5pm"), chrono::NaiveTime::from_hms_opt(17, 0, 0));
+        assert_eq!(parse_clock("9am"), chrono::NaiveTime::from_hms_opt(9, 0, 0));
+        assert_eq!(parse_clock("9 am"), chrono::NaiveTime::from_hms_opt(9, 0, 0));
+        assert_eq!(parse_clock("12am"), chrono::NaiveTime::from_hms_opt(0, 0, 0));
+        assert_eq!(parse_clock("12pm"), chrono::NaiveTime::from_hms_opt(12, 0, 0));
+    }
+
+    #[test]
+    fn parse_clock_with_minutes_and_24h() {
+        assert_eq!(
+            parse_clock("5:30pm"),
+            chrono::NaiveTime::from_hms_opt(17, 30, 0)
+        );
+        assert_eq!(
+            parse_clock("09:00 AM"),
+            chrono::NaiveTime::from_hms_opt(9, 0, 0)
+        );
+        assert_eq!(parse_clock("17:00"), chrono::NaiveTime::from_hms_opt(17, 0, 0));
+        assert_eq!(parse_clock("not a time"), None);
+    }
+
+    #[test]
+    fn parse_natural_time_yesterday_with_bare_meridiem() {
+        let ts = parse_natural_time("yesterday 5pm").expect("should parse");
+        let dt = Utc.timestamp_opt(ts, 0).single().unwrap();
+        assert_eq!(dt.date_naive(), (Utc::now() - Duration::days(1)).date_naive());
+        assert_eq!(dt.format("%H:%M").to_string(), "17:00");
     }
 }